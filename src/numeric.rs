@@ -1,19 +1,46 @@
 use super::Spec;
 
 use std::fmt::Debug;
-use std::cmp::PartialOrd;
+use std::cmp::{Ordering, PartialOrd};
 
-pub trait OrderedSpec<T>
-    where T: Debug + PartialOrd
+fn assert_bounds_ordered<E>(low: &E, high: &E)
+    where E: Debug + PartialOrd<E>
 {
-    fn is_less_than(&mut self, other: &T) -> &mut Self;
-    fn is_less_than_or_equal_to(&mut self, other: &T) -> &mut Self;
-    fn is_greater_than(&mut self, other: &T) -> &mut Self;
-    fn is_greater_than_or_equal_to(&mut self, other: &T) -> &mut Self;
+    let bounds_cmp = low.partial_cmp(high);
+    assert!(bounds_cmp.is_some(),
+            "low and high must be comparable, but were incomparable: low: <{:?}>, high: <{:?}>", low, high);
+    assert!(bounds_cmp != Some(Ordering::Greater),
+            "low must be less than or equal to high, but was low: <{:?}>, high: <{:?}>", low, high);
 }
 
-impl<'s, T> OrderedSpec<T> for Spec<'s, T>
-    where T: Debug + PartialOrd
+fn describe_between_actual<T>(subject: &T, cmp_low: Option<Ordering>, cmp_high: Option<Ordering>) -> String
+    where T: Debug
+{
+    if cmp_low.is_none() || cmp_high.is_none() {
+        format!("incomparable: <{:?}>", subject)
+    } else {
+        format!("<{:?}>", subject)
+    }
+}
+
+pub trait OrderedSpec<E>
+    where E: Debug + PartialOrd<E>
+{
+    fn is_less_than(&mut self, other: &E) -> &mut Self;
+    fn is_less_than_or_equal_to(&mut self, other: &E) -> &mut Self;
+    fn is_greater_than(&mut self, other: &E) -> &mut Self;
+    fn is_greater_than_or_equal_to(&mut self, other: &E) -> &mut Self;
+    fn is_between(&mut self, low: &E, high: &E) -> &mut Self;
+    fn is_strictly_between(&mut self, low: &E, high: &E) -> &mut Self;
+    fn is_not_less_than(&mut self, other: &E) -> &mut Self;
+    fn is_not_less_than_or_equal_to(&mut self, other: &E) -> &mut Self;
+    fn is_not_greater_than(&mut self, other: &E) -> &mut Self;
+    fn is_not_greater_than_or_equal_to(&mut self, other: &E) -> &mut Self;
+}
+
+impl<'s, T, E> OrderedSpec<E> for Spec<'s, T>
+    where T: Debug + PartialOrd<E>,
+          E: Debug + PartialOrd<E>
 {
     /// Asserts that the subject is less than the expected value. The subject type must
     /// implement `PartialOrd`.
@@ -21,13 +48,21 @@ impl<'s, T> OrderedSpec<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&1).is_less_than(&2);
     /// ```
-    fn is_less_than(&mut self, other: &T) -> &mut Self {
+    fn is_less_than(&mut self, other: &E) -> &mut Self {
         let subject = self.subject;
 
-        if subject >= other {
-            self.with_expected(format!("value less than <{:?}>", other))
-                .with_actual(format!("<{:?}>", subject))
-                .fail();
+        match subject.partial_cmp(other) {
+            Some(Ordering::Less) => (),
+            Some(_) => {
+                self.with_expected(format!("value less than <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            None => {
+                self.with_expected(format!("value less than <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
         }
 
         self
@@ -39,13 +74,21 @@ impl<'s, T> OrderedSpec<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_less_than_or_equal_to(&2);
     /// ```
-    fn is_less_than_or_equal_to(&mut self, other: &T) -> &mut Self {
+    fn is_less_than_or_equal_to(&mut self, other: &E) -> &mut Self {
         let subject = self.subject;
 
-        if subject > other {
-            self.with_expected(format!("value less than or equal to <{:?}>", other))
-                .with_actual(format!("<{:?}>", subject))
-                .fail();
+        match subject.partial_cmp(other) {
+            Some(Ordering::Less) | Some(Ordering::Equal) => (),
+            Some(_) => {
+                self.with_expected(format!("value less than or equal to <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            None => {
+                self.with_expected(format!("value less than or equal to <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
         }
 
         self
@@ -57,13 +100,21 @@ impl<'s, T> OrderedSpec<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_greater_than(&1);
     /// ```
-    fn is_greater_than(&mut self, other: &T) -> &mut Self {
+    fn is_greater_than(&mut self, other: &E) -> &mut Self {
         let subject = self.subject;
 
-        if subject <= other {
-            self.with_expected(format!("value greater than <{:?}>", other))
-                .with_actual(format!("<{:?}>", subject))
-                .fail();
+        match subject.partial_cmp(other) {
+            Some(Ordering::Greater) => (),
+            Some(_) => {
+                self.with_expected(format!("value greater than <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            None => {
+                self.with_expected(format!("value greater than <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
         }
 
         self
@@ -75,19 +126,225 @@ impl<'s, T> OrderedSpec<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_greater_than_or_equal_to(&1);
     /// ```
-    fn is_greater_than_or_equal_to(&mut self, other: &T) -> &mut Self {
+    fn is_greater_than_or_equal_to(&mut self, other: &E) -> &mut Self {
+        let subject = self.subject;
+
+        match subject.partial_cmp(other) {
+            Some(Ordering::Greater) | Some(Ordering::Equal) => (),
+            Some(_) => {
+                self.with_expected(format!("value greater than or equal to <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            None => {
+                self.with_expected(format!("value greater than or equal to <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is inclusively between the given low and high bounds. The
+    /// subject type must implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&5).is_between(&1, &10);
+    /// ```
+    fn is_between(&mut self, low: &E, high: &E) -> &mut Self {
+        assert_bounds_ordered(low, high);
+
+        let subject = self.subject;
+        let cmp_low = subject.partial_cmp(low);
+        let cmp_high = subject.partial_cmp(high);
+
+        let in_range = match (cmp_low, cmp_high) {
+            (Some(lo), Some(hi)) => lo != Ordering::Less && hi != Ordering::Greater,
+            _ => false,
+        };
+
+        if !in_range {
+            self.with_expected(format!("value between <{:?}> and <{:?}>", low, high))
+                .with_actual(describe_between_actual(subject, cmp_low, cmp_high))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is strictly between the given low and high bounds. The
+    /// subject type must implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&5).is_strictly_between(&1, &10);
+    /// ```
+    fn is_strictly_between(&mut self, low: &E, high: &E) -> &mut Self {
+        assert_bounds_ordered(low, high);
+
         let subject = self.subject;
+        let cmp_low = subject.partial_cmp(low);
+        let cmp_high = subject.partial_cmp(high);
+
+        let in_range = match (cmp_low, cmp_high) {
+            (Some(lo), Some(hi)) => lo == Ordering::Greater && hi == Ordering::Less,
+            _ => false,
+        };
 
-        if subject < other {
-            self.with_expected(format!("value greater than or equal to <{:?}>", other))
-                .with_actual(format!("<{:?}>", subject))
+        if !in_range {
+            self.with_expected(format!("value strictly between <{:?}> and <{:?}>", low, high))
+                .with_actual(describe_between_actual(subject, cmp_low, cmp_high))
                 .fail();
         }
 
         self
     }
+
+    /// Asserts that the subject is not less than the expected value. The subject type must
+    /// implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&2).is_not_less_than(&1);
+    /// ```
+    fn is_not_less_than(&mut self, other: &E) -> &mut Self {
+        let subject = self.subject;
+
+        match subject.partial_cmp(other) {
+            Some(Ordering::Less) => {
+                self.with_expected(format!("value not less than <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            Some(_) => (),
+            None => {
+                self.with_expected(format!("value not less than <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is not less than or equal to the expected value. The subject
+    /// type must implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&2).is_not_less_than_or_equal_to(&1);
+    /// ```
+    fn is_not_less_than_or_equal_to(&mut self, other: &E) -> &mut Self {
+        let subject = self.subject;
+
+        match subject.partial_cmp(other) {
+            Some(Ordering::Less) | Some(Ordering::Equal) => {
+                self.with_expected(format!("value not less than or equal to <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            Some(_) => (),
+            None => {
+                self.with_expected(format!("value not less than or equal to <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is not greater than the expected value. The subject type must
+    /// implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1).is_not_greater_than(&2);
+    /// ```
+    fn is_not_greater_than(&mut self, other: &E) -> &mut Self {
+        let subject = self.subject;
+
+        match subject.partial_cmp(other) {
+            Some(Ordering::Greater) => {
+                self.with_expected(format!("value not greater than <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            Some(_) => (),
+            None => {
+                self.with_expected(format!("value not greater than <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is not greater than or equal to the expected value. The
+    /// subject type must implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1).is_not_greater_than_or_equal_to(&2);
+    /// ```
+    fn is_not_greater_than_or_equal_to(&mut self, other: &E) -> &mut Self {
+        let subject = self.subject;
+
+        match subject.partial_cmp(other) {
+            Some(Ordering::Greater) | Some(Ordering::Equal) => {
+                self.with_expected(format!("value not greater than or equal to <{:?}>", other))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail();
+            }
+            Some(_) => (),
+            None => {
+                self.with_expected(format!("value not greater than or equal to <{:?}>", other))
+                    .with_actual(format!("incomparable: <{:?}>", subject))
+                    .fail();
+            }
+        }
+
+        self
+    }
 }
 
+pub trait CloseTo<T> {
+    fn is_close_to(&mut self, expected: &T, tolerance: &T) -> &mut Self;
+}
+
+macro_rules! impl_close_to {
+    ($float:ty) => {
+        impl<'s> CloseTo<$float> for Spec<'s, $float> {
+            /// Asserts that the subject is within `tolerance` of the expected value. Handles the
+            /// NaN and infinite edge cases explicitly: any NaN operand fails, and an infinite
+            /// subject only matches an equal infinite expected value.
+            ///
+            /// ```rust,ignore
+            /// assert_that(&3.14).is_close_to(&3.14, &0.001);
+            /// ```
+            fn is_close_to(&mut self, expected: &$float, tolerance: &$float) -> &mut Self {
+                let subject = self.subject;
+
+                let is_close = if subject.is_nan() || expected.is_nan() || tolerance.is_nan() {
+                    false
+                } else if subject.is_infinite() || expected.is_infinite() {
+                    subject == expected
+                } else {
+                    (subject - expected).abs() <= *tolerance
+                };
+
+                if !is_close {
+                    self.with_expected(format!("<{:?}> within <{:?}>", expected, tolerance))
+                        .with_actual(format!("<{:?}>", subject))
+                        .fail();
+                }
+
+                self
+            }
+        }
+    }
+}
+
+impl_close_to!(f64);
+impl_close_to!(f32);
+
 #[cfg(test)]
 mod tests {
 
@@ -139,4 +396,239 @@ mod tests {
         assert_that(&2).is_greater_than_or_equal_to(&3);
     }
 
+    #[derive(Debug)]
+    struct Meters(f64);
+
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Feet(f64);
+
+    impl PartialEq<Feet> for Meters {
+        fn eq(&self, other: &Feet) -> bool {
+            self.0 == other.0 * 0.3048
+        }
+    }
+
+    impl PartialOrd<Feet> for Meters {
+        fn partial_cmp(&self, other: &Feet) -> Option<::std::cmp::Ordering> {
+            self.0.partial_cmp(&(other.0 * 0.3048))
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_cross_type_value_is_less_than_expected() {
+        assert_that(&Meters(1.0)).is_less_than(&Feet(10.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value less than <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_less_than() {
+        assert_that(&f64::NAN).is_less_than(&2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value less than or equal to <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_less_than_or_equal_to() {
+        assert_that(&f64::NAN).is_less_than_or_equal_to(&2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value greater than <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_greater_than() {
+        assert_that(&f64::NAN).is_greater_than(&2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value greater than or equal to <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_greater_than_or_equal_to() {
+        assert_that(&f64::NAN).is_greater_than_or_equal_to(&2.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_between_bounds() {
+        assert_that(&5).is_between(&1, &10);
+        assert_that(&1).is_between(&1, &10);
+        assert_that(&10).is_between(&1, &10);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value between <1> and <10>\n\t but was: <42>")]
+    fn should_panic_if_value_is_not_between_bounds() {
+        assert_that(&42).is_between(&1, &10);
+    }
+
+    #[test]
+    #[should_panic(expected = "low must be less than or equal to high")]
+    fn should_panic_if_between_bounds_are_misordered() {
+        assert_that(&5).is_between(&10, &1);
+    }
+
+    #[test]
+    #[should_panic(expected = "low and high must be comparable, but were incomparable")]
+    fn should_panic_if_between_bounds_are_incomparable() {
+        assert_that(&5.0).is_between(&f64::NAN, &10.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value between <1.0> and <10.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_between() {
+        assert_that(&f64::NAN).is_between(&1.0, &10.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_strictly_between_bounds() {
+        assert_that(&5).is_strictly_between(&1, &10);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value strictly between <1> and <10>\n\t but was: <1>")]
+    fn should_panic_if_value_is_not_strictly_between_bounds() {
+        assert_that(&1).is_strictly_between(&1, &10);
+    }
+
+    #[test]
+    #[should_panic(expected = "low must be less than or equal to high")]
+    fn should_panic_if_strictly_between_bounds_are_misordered() {
+        assert_that(&5).is_strictly_between(&10, &1);
+    }
+
+    #[test]
+    #[should_panic(expected = "low and high must be comparable, but were incomparable")]
+    fn should_panic_if_strictly_between_bounds_are_incomparable() {
+        assert_that(&5.0).is_strictly_between(&f64::NAN, &10.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value strictly between <1.0> and <10.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_strictly_between() {
+        assert_that(&f64::NAN).is_strictly_between(&1.0, &10.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_close_to_expected() {
+        assert_that(&2.5).is_close_to(&2.5, &0.001);
+        assert_that(&2.5005).is_close_to(&2.5, &0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2.5> within <0.001>\n\t but was: <2.6>")]
+    fn should_panic_if_value_is_not_close_to_expected() {
+        assert_that(&2.6).is_close_to(&2.5, &0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2.5> within <0.001>\n\t but was: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_close_to() {
+        assert_that(&f64::NAN).is_close_to(&2.5, &0.001);
+    }
+
+    #[test]
+    fn should_not_panic_if_infinite_value_matches_infinite_expected() {
+        assert_that(&f64::INFINITY).is_close_to(&f64::INFINITY, &0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2.5> within <0.001>\n\t but was: <inf>")]
+    fn should_panic_if_infinite_value_does_not_match_finite_expected() {
+        assert_that(&f64::INFINITY).is_close_to(&2.5, &0.001);
+    }
+
+    #[test]
+    fn should_not_panic_if_f32_value_is_close_to_expected() {
+        assert_that(&2.5f32).is_close_to(&2.5f32, &0.001f32);
+        assert_that(&2.5005f32).is_close_to(&2.5f32, &0.001f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2.5> within <0.001>\n\t but was: <2.6>")]
+    fn should_panic_if_f32_value_is_not_close_to_expected() {
+        assert_that(&2.6f32).is_close_to(&2.5f32, &0.001f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2.5> within <0.001>\n\t but was: <NaN>")]
+    fn should_panic_if_f32_value_is_nan_for_is_close_to() {
+        assert_that(&f32::NAN).is_close_to(&2.5f32, &0.001f32);
+    }
+
+    #[test]
+    fn should_not_panic_if_infinite_f32_value_matches_infinite_expected() {
+        assert_that(&f32::INFINITY).is_close_to(&f32::INFINITY, &0.001f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2.5> within <0.001>\n\t but was: <inf>")]
+    fn should_panic_if_infinite_f32_value_does_not_match_finite_expected() {
+        assert_that(&f32::INFINITY).is_close_to(&2.5f32, &0.001f32);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_not_less_than_expected() {
+        assert_that(&2).is_not_less_than(&1);
+        assert_that(&2).is_not_less_than(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not less than <2>\n\t but was: <1>")]
+    fn should_panic_if_value_is_less_than_expected_for_is_not_less_than() {
+        assert_that(&1).is_not_less_than(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not less than <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_not_less_than() {
+        assert_that(&f64::NAN).is_not_less_than(&2.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_not_less_than_or_equal_to_expected() {
+        assert_that(&2).is_not_less_than_or_equal_to(&1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not less than or equal to <2>\n\t but was: <2>")]
+    fn should_panic_if_value_is_equal_for_is_not_less_than_or_equal_to() {
+        assert_that(&2).is_not_less_than_or_equal_to(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not less than or equal to <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_not_less_than_or_equal_to() {
+        assert_that(&f64::NAN).is_not_less_than_or_equal_to(&2.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_not_greater_than_expected() {
+        assert_that(&1).is_not_greater_than(&2);
+        assert_that(&2).is_not_greater_than(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not greater than <1>\n\t but was: <2>")]
+    fn should_panic_if_value_is_greater_than_expected_for_is_not_greater_than() {
+        assert_that(&2).is_not_greater_than(&1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not greater than <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_not_greater_than() {
+        assert_that(&f64::NAN).is_not_greater_than(&2.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_not_greater_than_or_equal_to_expected() {
+        assert_that(&1).is_not_greater_than_or_equal_to(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not greater than or equal to <2>\n\t but was: <2>")]
+    fn should_panic_if_value_is_equal_for_is_not_greater_than_or_equal_to() {
+        assert_that(&2).is_not_greater_than_or_equal_to(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value not greater than or equal to <2.0>\n\t but was: incomparable: <NaN>")]
+    fn should_panic_if_value_is_nan_for_is_not_greater_than_or_equal_to() {
+        assert_that(&f64::NAN).is_not_greater_than_or_equal_to(&2.0);
+    }
+
 }
\ No newline at end of file